@@ -1,82 +1,237 @@
 use std::fs;
 use std::path::Path;
-use tree_sitter::{Parser, Tree};
+use serde::{Serialize, Deserialize};
+use tree_sitter::{Node, Parser, Tree};
 use tree_sitter_python::LANGUAGE;
 
 const FUNCTION_CALLS: [&str; 2] = ["path", "re_path"];
+const INCLUDE_CALL: &str = "include";
 
+/// A single `path()`/`re_path()` entry found in a `urls.py`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UrlPattern {
+    /// First positional argument: the route string, e.g. `"profile/"`.
+    pub route: Option<String>,
+    /// Second positional argument, as written (e.g. `views.profile`).
+    pub view: Option<String>,
+    /// The local `name=` keyword argument, if any.
+    pub name: Option<String>,
+    /// `name` prefixed with any enclosing `include(..., namespace=...)`
+    /// namespaces, e.g. `blog:post-detail` — how Django resolves reverse
+    /// names.
+    pub qualified_name: Option<String>,
+}
+
+/// A single `include(...)` call found in a `urls.py`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncludeEntry {
+    /// The route this include is mounted under, taken from the enclosing
+    /// `path()`/`re_path()` call, if any.
+    pub route: Option<String>,
+    /// The included module path (or app label), e.g. `"blog.urls"`.
+    pub module: Option<String>,
+    /// `namespace=` keyword argument, if any.
+    pub namespace: Option<String>,
+    /// `app_name=` keyword argument, if any.
+    pub app_name: Option<String>,
+}
 
-pub fn extract_url_patterns(file_path: &Path) -> Vec<String> {
-    let mut patterns = Vec::new();
+/// Everything extracted from one `urls.py` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedUrls {
+    pub patterns: Vec<UrlPattern>,
+    pub includes: Vec<IncludeEntry>,
+}
+
+pub fn extract_url_patterns(file_path: &Path) -> ExtractedUrls {
+    let mut extracted = ExtractedUrls::default();
 
     let source_code = match fs::read_to_string(file_path) {
         Ok(code) => code,
-        Err(_) => return patterns,
+        Err(_) => return extracted,
     };
-	
+
     let mut parser = Parser::new();
     parser.set_language(&LANGUAGE.into()).expect("Error loading Tree-sitter Python");
 
     let tree = match parser.parse(&source_code, None) {
         Some(tree) => tree,
-        None => return patterns,
+        None => return extracted,
     };
 
-    extract_from_tree(&tree, &source_code, &mut patterns);
+    extract_from_tree(&tree, &source_code, &mut extracted);
 
-    patterns
+    extracted
 }
 
-pub fn extract_from_tree(tree: &Tree, source_code: &str, patterns: &mut Vec<String>) {
-    let mut cursor = tree.walk();
+pub fn extract_from_tree(tree: &Tree, source_code: &str, extracted: &mut ExtractedUrls) {
+    walk_node(tree.root_node(), source_code, &[], extracted);
+}
 
-    // Recursively traverse the AST
-    let mut stack = vec![cursor.node()];
+/// Recursively walks the AST, threading a stack of namespaces gathered from
+/// enclosing `include(..., namespace=...)` calls down to nested patterns so
+/// their fully-qualified reverse name can be built.
+fn walk_node(node: Node, source_code: &str, namespace_stack: &[String], extracted: &mut ExtractedUrls) {
+    let mut child_namespace_stack = None;
 
-    while let Some(node) = stack.pop() {
-        if node.kind() == "call" {
-            if let Some(pattern_name) = extract_pattern_name(node, source_code) {
-                patterns.push(pattern_name);
+    if node.kind() == "call" {
+        if let Some(func_name) = call_function_name(node, source_code) {
+            if func_name == INCLUDE_CALL {
+                if let Some(include_entry) = extract_include_entry(node, source_code) {
+                    if let Some(namespace) = include_entry
+                        .namespace
+                        .clone()
+                        .or_else(|| include_entry.app_name.clone())
+                    {
+                        let mut nested = namespace_stack.to_vec();
+                        nested.push(namespace);
+                        child_namespace_stack = Some(nested);
+                    }
+                    extracted.includes.push(include_entry);
+                }
+            } else if FUNCTION_CALLS.contains(&func_name.as_str()) {
+                if let Some(pattern) = extract_pattern(node, source_code, namespace_stack) {
+                    extracted.patterns.push(pattern);
+                }
             }
         }
+    }
 
-        // Push child nodes to process next
-        stack.extend(node.children(&mut cursor));
+    let stack_for_children: &[String] = child_namespace_stack.as_deref().unwrap_or(namespace_stack);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        walk_node(child, source_code, stack_for_children, extracted);
     }
 }
 
-pub fn extract_pattern_name(node: tree_sitter::Node, source_code: &str) -> Option<String> {
-    let mut func_name = None;
-    let mut name_arg = None;
+/// Returns a call node's function name, unwrapping attribute access
+/// (`django.urls.path` -> `path`) so aliasing/qualified imports still match.
+fn call_function_name(node: Node, source_code: &str) -> Option<String> {
+    let function_node = node.child_by_field_name("function")?;
+    let text = &source_code[function_node.byte_range()];
+    Some(text.rsplit('.').next().unwrap_or(text).to_string())
+}
 
-    for child in node.children(&mut node.walk()) {
-        if child.kind() == "identifier" {
-            let text = &source_code[child.byte_range()];
-            if FUNCTION_CALLS.contains(&text) {
-                func_name = Some(text.to_string());
-            }
-        } else if child.kind() == "argument_list" {
-            for arg in child.children(&mut child.walk()) {
-                if arg.kind() == "keyword_argument" {
-                    let arg_text = &source_code[arg.byte_range()];
-                    if arg_text.contains("name=") {
-                        let parts: Vec<&str> = arg_text.split('=').collect();
-                        if parts.len() == 2 {
-                            name_arg = Some(parts[1].trim_matches(|c: char| c == '"' || c == '\'').to_string());
-                        }
-                    }
-                }
+/// Named (non-punctuation) children of an `argument_list` that aren't
+/// keyword arguments, in source order.
+fn positional_args<'a>(argument_list: Node<'a>) -> Vec<Node<'a>> {
+    let mut cursor = argument_list.walk();
+    argument_list
+        .named_children(&mut cursor)
+        .filter(|arg| arg.kind() != "keyword_argument")
+        .collect()
+}
+
+/// Finds a `keyword_argument` child of `argument_list` named `keyword` and
+/// returns its value as a string, reading it from the value node's own
+/// string-literal child rather than splitting the raw argument text.
+fn keyword_string_value(argument_list: Node, source_code: &str, keyword: &str) -> Option<String> {
+    let mut cursor = argument_list.walk();
+    let result = argument_list
+        .named_children(&mut cursor)
+        .filter(|arg| arg.kind() == "keyword_argument")
+        .find_map(|arg| {
+            let name_node = arg.child_by_field_name("name")?;
+            if &source_code[name_node.byte_range()] != keyword {
+                return None;
             }
+            let value_node = arg.child_by_field_name("value")?;
+            string_literal_value(value_node, source_code)
+        });
+    result
+}
+
+/// Extracts the contents of a Python string node, stripping quotes (and any
+/// `r`/`u`/`b` prefix) rather than trimming the raw argument text.
+fn string_literal_value(node: Node, source_code: &str) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "string_content" {
+            return Some(source_code[child.byte_range()].to_string());
         }
     }
 
-    if func_name.is_some() && name_arg.is_some() {
-        name_arg
+    // No `string_content` node (an empty string, or an older grammar): strip
+    // the prefix and quotes by hand.
+    let text = source_code[node.byte_range()].trim_start_matches(|c: char| "rRuUbB".contains(c));
+    Some(text.trim_matches(|c: char| c == '"' || c == '\'').to_string())
+}
+
+/// Pulls a module path out of `include()`'s first positional argument, which
+/// is either a bare string (`include("blog.urls")`) or a 2-tuple whose first
+/// element is a string (`include(("blog.urls", "blog"))`). Deliberately does
+/// NOT recurse into list literals: `include([path(...), ...], namespace=...)`
+/// has no module at all, just inline sub-patterns.
+fn module_string_value(node: Node, source_code: &str) -> Option<String> {
+    if let Some(value) = string_literal_value(node, source_code) {
+        return Some(value);
+    }
+
+    if matches!(node.kind(), "tuple" | "parenthesized_expression") {
+        let mut cursor = node.walk();
+        let first = node.named_children(&mut cursor).next()?;
+        return string_literal_value(first, source_code);
+    }
+
+    None
+}
+
+/// If `node` (an `include(...)` call) sits as the second argument of an
+/// enclosing `path()`/`re_path()` call, returns that call's route.
+fn enclosing_route(node: Node, source_code: &str) -> Option<String> {
+    let argument_list = node.parent()?;
+    if argument_list.kind() != "argument_list" {
+        return None;
+    }
+    let call = argument_list.parent()?;
+    if call.kind() != "call" {
+        return None;
+    }
+    let func_name = call_function_name(call, source_code)?;
+    if !FUNCTION_CALLS.contains(&func_name.as_str()) {
+        return None;
+    }
+
+    positional_args(argument_list)
+        .first()
+        .and_then(|route_node| string_literal_value(*route_node, source_code))
+}
+
+fn qualify_name(namespace_stack: &[String], name: &str) -> String {
+    if namespace_stack.is_empty() {
+        name.to_string()
     } else {
-        None
+        format!("{}:{}", namespace_stack.join(":"), name)
     }
 }
 
+fn extract_pattern(node: Node, source_code: &str, namespace_stack: &[String]) -> Option<UrlPattern> {
+    let argument_list = node.child_by_field_name("arguments")?;
+    let positional = positional_args(argument_list);
+
+    let route = positional.first().and_then(|n| string_literal_value(*n, source_code));
+    let view = positional.get(1).map(|n| source_code[n.byte_range()].trim().to_string());
+    let name = keyword_string_value(argument_list, source_code, "name");
+    let qualified_name = name.as_deref().map(|n| qualify_name(namespace_stack, n));
+
+    Some(UrlPattern { route, view, name, qualified_name })
+}
+
+fn extract_include_entry(node: Node, source_code: &str) -> Option<IncludeEntry> {
+    let argument_list = node.child_by_field_name("arguments")?;
+    let positional = positional_args(argument_list);
+
+    let module = positional.first().and_then(|n| module_string_value(*n, source_code));
+    let namespace = keyword_string_value(argument_list, source_code, "namespace");
+    let app_name = keyword_string_value(argument_list, source_code, "app_name");
+    let route = enclosing_route(node, source_code);
+
+    Some(IncludeEntry { route, module, namespace, app_name })
+}
 
 #[cfg(test)]
 mod tests {
@@ -90,6 +245,10 @@ mod tests {
         file
     }
 
+    fn names(extracted: &ExtractedUrls) -> Vec<String> {
+        extracted.patterns.iter().filter_map(|p| p.name.clone()).collect()
+    }
+
     #[test]
     fn test_extract_basic_urls() {
         let temp_file = create_temp_urls_py(
@@ -102,8 +261,9 @@ mod tests {
             "#,
         );
 
-        let mut urls = extract_url_patterns(temp_file.path());
-        assert_eq!(urls.sort(), vec!["home", "user-profile"].sort());
+        let mut urls = names(&extract_url_patterns(temp_file.path()));
+        urls.sort();
+        assert_eq!(urls, vec!["home", "user-profile"]);
     }
 
     #[test]
@@ -117,12 +277,13 @@ mod tests {
             "#,
         );
 
-        let urls = extract_url_patterns(temp_file.path());
-        assert_eq!(urls, vec!["dashboard"]);
+        let extracted = extract_url_patterns(temp_file.path());
+        assert_eq!(names(&extracted), vec!["dashboard"]);
+        assert_eq!(extracted.patterns[0].route.as_deref(), Some("^dashboard/$"));
     }
 
     #[test]
-    fn test_ignore_include_urls() {
+    fn test_include_produces_entry_not_a_name() {
         let temp_file = create_temp_urls_py(
             r#"
             from django.urls import path, include
@@ -133,8 +294,37 @@ mod tests {
             "#,
         );
 
-        let urls = extract_url_patterns(temp_file.path());
-        assert_eq!(urls, vec!["home"]); // Ensure it ignores `include()`
+        let extracted = extract_url_patterns(temp_file.path());
+        assert_eq!(names(&extracted), vec!["home"]); // `include()` contributes no name
+        assert_eq!(extracted.includes.len(), 1);
+        assert_eq!(extracted.includes[0].module.as_deref(), Some("blog.urls"));
+        assert_eq!(extracted.includes[0].route.as_deref(), Some("blog/"));
+    }
+
+    #[test]
+    fn test_include_namespace_qualifies_nested_names() {
+        let temp_file = create_temp_urls_py(
+            r#"
+            from django.urls import path, include
+            urlpatterns = [
+                path("blog/", include([
+                    path("", views.post_list, name="post-list"),
+                    path("<slug>/", views.post_detail, name="post-detail"),
+                ], namespace="blog")),
+            ]
+            "#,
+        );
+
+        let extracted = extract_url_patterns(temp_file.path());
+        let qualified: Vec<_> = extracted
+            .patterns
+            .iter()
+            .filter_map(|p| p.qualified_name.clone())
+            .collect();
+        assert_eq!(qualified, vec!["blog:post-list", "blog:post-detail"]);
+        assert_eq!(extracted.includes[0].namespace.as_deref(), Some("blog"));
+        // Inline sub-patterns, not a module reference: there's no module string here.
+        assert_eq!(extracted.includes[0].module, None);
     }
 
     #[test]
@@ -146,7 +336,8 @@ mod tests {
             "#,
         );
 
-        let urls = extract_url_patterns(temp_file.path());
-        assert!(urls.is_empty());
+        let extracted = extract_url_patterns(temp_file.path());
+        assert!(extracted.patterns.is_empty());
+        assert!(extracted.includes.is_empty());
     }
 }
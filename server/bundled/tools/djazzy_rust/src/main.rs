@@ -1,24 +1,290 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
 use chrono::{DateTime, Utc};
+use globset::{GlobBuilder, GlobMatcher};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use rayon::prelude::*;
 use serde::{Serialize, Deserialize};
-use serde_json;
 
-use djazzy_rust::extract_url_patterns;
-
-const FILE_TYPES: [&str; 1] = ["urls.py"];
-const IGNORED_DIRS: [&str; 4] = [
-    ".venv",       
-    "node_modules",   
-    "__pycache__",    
-    "migrations",     
+use djazzy_rust::{extract_url_patterns, IncludeEntry, UrlPattern};
+
+const DEFAULT_INCLUDE_GLOBS: [&str; 1] = ["**/urls.py"];
+// These match the directory itself (not just its contents): a full scan asks
+// `is_ignored` about a directory before recursing into it, and watch mode asks
+// about a touched file and each of its ancestor directories.
+const DEFAULT_IGNORE_GLOBS: [&str; 4] = [
+    "**/.venv",
+    "**/node_modules",
+    "**/__pycache__",
+    "**/migrations",
 ];
+const IGNORE_FILE_NAMES: [&str; 2] = [".gitignore", ".djazzyignore"];
+const CONFIG_FILE_NAME: &str = "djazzy.json";
+
+/// A glob split into a literal base directory and the glob that's matched
+/// against paths relative to it, so we only ever test files that could
+/// plausibly live under that base. Used for both include and ignore globs,
+/// so an ignore pattern like `apps/*/tests` is anchored to `root` the same
+/// way an include pattern is, instead of being tested against the full
+/// absolute path.
+struct PathMatcher {
+    base_path: PathBuf,
+    matcher: GlobMatcher,
+}
+
+/// Include/exclude glob configuration for the `urls.py` scan, compiled once
+/// up front so traversal never has to re-parse a pattern.
+pub struct ScanConfig {
+    include: Vec<PathMatcher>,
+    ignore: Vec<PathMatcher>,
+    respect_ignore_files: bool,
+}
+
+impl ScanConfig {
+    /// Builds a config from raw include/ignore glob strings, anchoring each
+    /// glob's literal prefix under `root`.
+    fn new(root: &Path, include: &[String], ignore: &[String]) -> Self {
+        let include = include
+            .iter()
+            .filter_map(|pattern| compile_path_matcher(root, pattern))
+            .collect();
+        let ignore = ignore
+            .iter()
+            .filter_map(|pattern| compile_path_matcher(root, pattern))
+            .collect();
+
+        Self { include, ignore, respect_ignore_files: true }
+    }
+
+    /// Reproduces the historical hardcoded `FILE_TYPES`/`IGNORED_DIRS` behavior.
+    #[cfg(test)]
+    fn default_for(root: &Path) -> Self {
+        let include: Vec<String> = DEFAULT_INCLUDE_GLOBS.iter().map(|s| s.to_string()).collect();
+        let ignore: Vec<String> = DEFAULT_IGNORE_GLOBS.iter().map(|s| s.to_string()).collect();
+        Self::new(root, &include, &ignore)
+    }
+
+    /// Discovers the nearest `djazzy.json` above `project_root` (via
+    /// `finder`) and builds a config from it, falling back to the defaults
+    /// above for any field the file doesn't set. Also returns the cache path
+    /// the file config requests, if any.
+    fn resolve(project_root: &Path, finder: &mut ConfigFinder) -> (Self, PathBuf) {
+        let file_config = finder
+            .find_config(project_root)
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str::<DjazzyFileConfig>(&contents).ok())
+            .unwrap_or_default();
+
+        let mut include = if file_config.include.is_empty() {
+            DEFAULT_INCLUDE_GLOBS.iter().map(|s| s.to_string()).collect()
+        } else {
+            file_config.include
+        };
+        include.extend(file_config.url_filenames.iter().map(|name| format!("**/{}", name)));
+
+        let ignore = if file_config.ignore.is_empty() {
+            DEFAULT_IGNORE_GLOBS.iter().map(|s| s.to_string()).collect()
+        } else {
+            file_config.ignore
+        };
+
+        let cache_path = file_config
+            .cache_path
+            .map(PathBuf::from)
+            .map(|path| if path.is_absolute() { path } else { project_root.join(path) })
+            .unwrap_or_else(|| project_root.join(".djazzy_cache.json"));
+
+        (Self::new(project_root, &include, &ignore), cache_path)
+    }
+
+    /// Whether `dir` still needs descending into because it lies under, or on
+    /// the path to, one of the include bases; `false` means skip it outright.
+    fn could_contain_matches(&self, dir: &Path) -> bool {
+        self.include
+            .iter()
+            .any(|inc| dir.starts_with(&inc.base_path) || inc.base_path.starts_with(dir))
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        self.ignore.iter().any(|rule| {
+            path.strip_prefix(&rule.base_path)
+                .map(|rel| rule.matcher.is_match(rel))
+                .unwrap_or(false)
+        })
+    }
+
+    fn matches_include(&self, path: &Path) -> bool {
+        self.include.iter().any(|inc| {
+            path.strip_prefix(&inc.base_path)
+                .map(|rel| inc.matcher.is_match(rel))
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// On-disk shape of `djazzy.json`: include/ignore globs, extra URL module
+/// filenames, and where to put the cache file, all optional so a partial
+/// file only overrides what it sets.
+#[derive(Deserialize, Default)]
+struct DjazzyFileConfig {
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    ignore: Vec<String>,
+    #[serde(default)]
+    url_filenames: Vec<String>,
+    cache_path: Option<String>,
+}
+
+/// Walks upward from a starting directory looking for `djazzy.json`,
+/// remembering which directories came up empty so a later lookup starting
+/// from a sibling or descendant doesn't re-stat them.
+struct ConfigFinder {
+    checked_without_config: HashSet<PathBuf>,
+}
+
+impl ConfigFinder {
+    fn new() -> Self {
+        Self { checked_without_config: HashSet::new() }
+    }
+
+    fn find_config(&mut self, start: &Path) -> Option<PathBuf> {
+        let mut found = None;
+        let mut newly_checked = Vec::new();
+
+        for dir in start.ancestors() {
+            if self.checked_without_config.contains(dir) {
+                continue; // Already known to hold no config; skip the stat.
+            }
+
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                found = Some(candidate);
+                break;
+            }
+
+            newly_checked.push(dir.to_path_buf());
+        }
+
+        self.checked_without_config.extend(newly_checked);
+        found
+    }
+}
+
+/// Splits a glob pattern like `app/*/urls.py` into its literal base
+/// directory (`app`) and the remaining pattern matched relative to it
+/// (`*/urls.py`), so the base can be checked with a cheap prefix test
+/// before the glob itself ever runs.
+fn split_glob_base(pattern: &str) -> (PathBuf, String) {
+    let is_glob_special = |part: &str| part.contains(['*', '?', '[', '{']);
+
+    let parts: Vec<&str> = pattern.split('/').collect();
+    let mut split_at = 0;
+    while split_at < parts.len() && !is_glob_special(parts[split_at]) {
+        split_at += 1;
+    }
+
+    if split_at == parts.len() {
+        // No glob characters at all; treat the whole pattern as a literal path:
+        // its parent directories are the base, its last component the pattern.
+        split_at = parts.len().saturating_sub(1);
+    }
+
+    let base = parts[..split_at].iter().collect::<PathBuf>();
+    let rel_pattern = parts[split_at..].join("/");
+
+    (base, rel_pattern)
+}
+
+/// Compiles `pattern` (anchored to `root` via `split_glob_base`) with
+/// `literal_separator` enabled, so a bare `*` behaves like a normal
+/// shell/glob wildcard and only matches within a single path component
+/// instead of crossing directory boundaries like `**` does.
+fn compile_path_matcher(root: &Path, pattern: &str) -> Option<PathMatcher> {
+    // A pattern with no `/` names a single component with nowhere to anchor
+    // it, so follow `.gitignore` convention and match it at any depth, e.g.
+    // `"node_modules"` behaves like `"**/node_modules"`.
+    let pattern = if pattern.contains('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{}", pattern)
+    };
+    let (base, rel_pattern) = split_glob_base(&pattern);
+    let base_path = if base.as_os_str().is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(base)
+    };
+    let matcher = GlobBuilder::new(&rel_pattern)
+        .literal_separator(true)
+        .build()
+        .ok()?
+        .compile_matcher();
+
+    Some(PathMatcher { base_path, matcher })
+}
+
+/// Parses any `.gitignore`/`.djazzyignore` present directly in `dir` into a
+/// single matcher scoped to that directory, or `None` if neither exists.
+fn load_dir_ignore_file(dir: &Path) -> Option<Gitignore> {
+    let mut builder = GitignoreBuilder::new(dir);
+    let mut found_any = false;
+
+    for name in IGNORE_FILE_NAMES {
+        let candidate = dir.join(name);
+        if candidate.is_file() {
+            found_any = builder.add(&candidate).is_none() || found_any;
+        }
+    }
+
+    if found_any {
+        builder.build().ok()
+    } else {
+        None
+    }
+}
+
+/// Checks `path` against the ignore-file stack gathered so far, deepest
+/// directory first, so a nested ignore file's negation can override a
+/// broader exclude declared higher up the tree.
+fn is_ignored_by_stack(stack: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    for matcher in stack.iter().rev() {
+        match matcher.matched(path, is_dir) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => continue,
+        }
+    }
+    false
+}
+
+/// Like `is_ignored_by_stack`, but also checks `path`'s ancestor directories
+/// up to the stack's root. A full scan never needs this: it walks the
+/// hierarchy top-down and prunes an ignored directory before ever looking
+/// inside it. Watch mode only ever sees a single touched file in isolation,
+/// so a rule like `vendor/` has to be checked against the file's ancestors
+/// too, not just the file path itself.
+fn is_ignored_by_stack_or_ancestors(stack: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    for matcher in stack.iter().rev() {
+        match matcher.matched_path_or_any_parents(path, is_dir) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => continue,
+        }
+    }
+    false
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 struct UrlEntry {
-    patterns: Vec<String>,
+    patterns: Vec<UrlPattern>,
+    includes: Vec<IncludeEntry>,
     mtime: DateTime<Utc>,
 }
 
@@ -48,68 +314,135 @@ impl Cache {
     }
 
     fn save_to_file(&self, cache_path: &Path) {
+        // `cache_path` may point somewhere other than the project root (a
+        // `djazzy.json` `cache_path` entry), so the directory it lives in
+        // isn't guaranteed to exist yet.
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create cache directory");
+        }
         fs::write(cache_path, serde_json::to_string_pretty(self).expect("Failed to serialize cache"))
             .expect("Failed to write cache file");
     }
 }
 
-fn find_urls_py_files(root: &Path, existing_cache: &mut HashMap<String, UrlEntry>) -> HashMap<String, UrlEntry> {
-    let mut results = HashMap::new();
+/// Walks `root`, applies the mtime cache shortcut for unchanged files, and
+/// runs `extract_url_patterns` for everything else concurrently across a
+/// bounded thread pool so cold-cache scans scale with available cores.
+fn find_urls_py_files(root: &Path, config: &ScanConfig, existing_cache: &mut HashMap<String, UrlEntry>) -> HashMap<String, UrlEntry> {
+    let candidates = collect_candidate_files(root, config);
+
+    let mut results = HashMap::with_capacity(candidates.len());
+    let mut to_process = Vec::new();
+
+    for path in candidates {
+        let key = path.to_string_lossy().to_string();
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        let modified_time: DateTime<Utc> = match metadata.modified() {
+            Ok(modified) => modified.into(),
+            Err(_) => continue,
+        };
+
+        // Check if file has changed
+        if let Some(existing_entry) = existing_cache.get(&key) {
+            if existing_entry.mtime == modified_time {
+                results.insert(key, existing_entry.clone());
+                continue; // Skip re-processing unchanged files
+            }
+        }
+
+        // File changed or not in cache -> reprocess
+        to_process.push((key, path, modified_time));
+    }
+
+    let processed: Vec<(String, UrlEntry)> = to_process
+        .into_par_iter()
+        .map(|(key, path, modified_time)| {
+            let extracted = extract_url_patterns(&path);
+            (key, UrlEntry { patterns: extracted.patterns, includes: extracted.includes, mtime: modified_time })
+        })
+        .collect();
+    results.extend(processed);
+
+    results
+}
+
+/// Recursively gathers every file under `root` that survives the
+/// include/ignore configuration, without parsing any of them.
+fn collect_candidate_files(root: &Path, config: &ScanConfig) -> Vec<PathBuf> {
+    collect_candidate_files_inner(root, config, &[])
+}
+
+fn collect_candidate_files_inner(root: &Path, config: &ScanConfig, ignore_stack: &[Gitignore]) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
 
     if root.is_dir() {
+        // Extend the ignore stack with any ignore file declared in this
+        // directory before descending, so its rules apply to everything below.
+        let mut local_stack;
+        let ignore_stack = if config.respect_ignore_files {
+            if let Some(matcher) = load_dir_ignore_file(root) {
+                local_stack = ignore_stack.to_vec();
+                local_stack.push(matcher);
+                &local_stack[..]
+            } else {
+                ignore_stack
+            }
+        } else {
+            ignore_stack
+        };
+
         for entry in fs::read_dir(root).unwrap() {
             let entry = entry.unwrap();
             let path = entry.path();
-            
-            // Skip ignored directories
-            if path.is_dir() && path.file_name()
-                .map_or(false, |name| IGNORED_DIRS.contains(&name.to_str().unwrap_or(""))) {
-                continue;
-            }
 
             if path.is_dir() {
-                results.extend(find_urls_py_files(&path, existing_cache));
-            } else if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                if FILE_TYPES.contains(&file_name) {
-                    let metadata = fs::metadata(&path).expect("Failed to get file metadata");
-                    let modified_time: DateTime<Utc> = metadata.modified().expect("Failed to get modified time").into();
-
-                    // Check if file has changed
-                    if let Some(existing_entry) = existing_cache.get(&path.to_string_lossy().to_string()) {
-                        if existing_entry.mtime == modified_time {
-                            results.insert(path.to_string_lossy().to_string(), existing_entry.clone());
-                            continue; // Skip re-processing unchanged files
-                        }
-                    }
-
-                    // File changed or not in cache -> reprocess
-                    results.insert(
-                        path.to_string_lossy().to_string(),
-                        UrlEntry {
-                            patterns: extract_url_patterns(&path),
-                            mtime: modified_time,
-                        }
-                    );
+                // Skip ignored directories, and don't bother descending into
+                // directories that can't possibly lead to an include match.
+                if config.is_ignored(&path)
+                    || is_ignored_by_stack(ignore_stack, &path, true)
+                    || !config.could_contain_matches(&path)
+                {
+                    continue;
                 }
+
+                candidates.extend(collect_candidate_files_inner(&path, config, ignore_stack));
+            } else if config.matches_include(&path) && !is_ignored_by_stack(ignore_stack, &path, false) {
+                candidates.push(path);
             }
         }
     }
-    results
+
+    candidates
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: djazzy_rust <project_root>");
+    let positional: Vec<&String> = args[1..].iter().filter(|a| a.as_str() != WATCH_FLAG).collect();
+    if positional.is_empty() {
+        eprintln!("Usage: djazzy_rust <project_root> [{}]", WATCH_FLAG);
         std::process::exit(1);
     }
+    let watch = args[1..].iter().any(|a| a == WATCH_FLAG);
+
+    // Resolve the project root against the working directory we started in,
+    // so a later `chdir` elsewhere in the process can't move the goalposts.
+    let initial_cwd = env::current_dir().expect("Failed to resolve working directory");
+    let project_root_arg = Path::new(positional[0]);
+    let project_root = if project_root_arg.is_absolute() {
+        project_root_arg.to_path_buf()
+    } else {
+        initial_cwd.join(project_root_arg)
+    };
 
-    let project_root = Path::new(&args[1]);
-    let cache_path = project_root.join(".djazzy_cache.json");
+    let mut config_finder = ConfigFinder::new();
+    let (scan_config, cache_path) = ScanConfig::resolve(&project_root, &mut config_finder);
 
     let mut cache = Cache::load_from_file(&cache_path);
 
-    let updated_urls = find_urls_py_files(project_root, &mut cache.urls);
+    let updated_urls = find_urls_py_files(&project_root, &scan_config, &mut cache.urls);
 
     cache.urls.extend(updated_urls);
     cache.last_modified_at = Utc::now();
@@ -117,6 +450,138 @@ fn main() {
     cache.save_to_file(&cache_path);
 
     println!("✅ Djazzy Rust cache updated: {}", cache_path.to_string_lossy());
+
+    if watch {
+        run_watch(&project_root, &scan_config, &cache_path, cache);
+    }
+}
+
+const WATCH_FLAG: &str = "--watch";
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `project_root` for `urls.py` creations/edits/deletions and keeps
+/// `cache` (and the on-disk cache file) up to date incrementally, instead of
+/// requiring a fresh full scan after every save.
+fn run_watch(project_root: &Path, config: &ScanConfig, cache_path: &Path, mut cache: Cache) {
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx).expect("Failed to create filesystem watcher");
+    watcher
+        .watch(project_root, RecursiveMode::Recursive)
+        .expect("Failed to watch project root");
+
+    println!("👀 Watching {} for urls.py changes (Ctrl+C to stop)...", project_root.display());
+
+    while let Ok(first_event) = rx.recv() {
+        let mut touched = HashSet::new();
+        collect_touched_paths(first_event, &mut touched);
+
+        // Collapse a burst of saves (e.g. an editor writing + renaming) into
+        // one update by draining further events within the debounce window.
+        let deadline = Instant::now() + WATCH_DEBOUNCE;
+        loop {
+            let remaining = match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => break,
+            };
+            match rx.recv_timeout(remaining) {
+                Ok(event) => collect_touched_paths(event, &mut touched),
+                Err(_) => break,
+            }
+        }
+
+        if touched.is_empty() {
+            continue;
+        }
+
+        apply_watch_changes(project_root, config, &mut cache, &touched);
+        cache.last_modified_at = Utc::now();
+        cache.save_to_file(cache_path);
+        println!("✅ Djazzy Rust cache updated: {}", cache_path.to_string_lossy());
+    }
+}
+
+fn collect_touched_paths(event: notify::Result<Event>, touched: &mut HashSet<PathBuf>) {
+    let event = match event {
+        Ok(event) => event,
+        Err(_) => return,
+    };
+
+    if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+        touched.extend(event.paths);
+    }
+}
+
+/// Rebuilds the `.gitignore`/`.djazzyignore` stack that would apply to
+/// `dir` during a full scan, by walking down from `project_root` loading
+/// whatever ignore files exist at each level. Watch mode only ever touches
+/// a handful of paths per debounce window, so redoing this per call (rather
+/// than caching the stack the initial scan built and discarded) is cheap.
+fn ignore_stack_for_dir(project_root: &Path, dir: &Path) -> Vec<Gitignore> {
+    let mut dirs: Vec<&Path> = dir.ancestors().take_while(|d| d.starts_with(project_root)).collect();
+    dirs.reverse();
+    dirs.iter().filter_map(|d| load_dir_ignore_file(d)).collect()
+}
+
+/// Whether a full scan would have pruned `path` before ever reaching it,
+/// either because one of its ancestor directories (up to `project_root`)
+/// matches a hardcoded/configured ignore glob, or because the gitignore
+/// stack rooted at `project_root` excludes it. A full scan only ever has
+/// to check the glob/gitignore rules against the directory it's about to
+/// descend into; watch mode sees a single file in isolation, so it has to
+/// walk that same ancestor chain itself instead.
+fn is_pruned_for_watch(project_root: &Path, config: &ScanConfig, path: &Path) -> bool {
+    if config.is_ignored(path) {
+        return true;
+    }
+    if path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(project_root))
+        .any(|dir| config.is_ignored(dir))
+    {
+        return true;
+    }
+
+    if config.respect_ignore_files {
+        let dir = path.parent().unwrap_or(project_root);
+        let ignore_stack = ignore_stack_for_dir(project_root, dir);
+        if is_ignored_by_stack_or_ancestors(&ignore_stack, path, false) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Re-extracts patterns for every touched file still under include/ignore
+/// rules, and drops cache entries for files that no longer exist.
+fn apply_watch_changes(project_root: &Path, config: &ScanConfig, cache: &mut Cache, touched: &HashSet<PathBuf>) {
+    for path in touched {
+        let key = path.to_string_lossy().to_string();
+
+        if !path.exists() {
+            cache.urls.remove(&key);
+            continue;
+        }
+
+        if !config.matches_include(path) || is_pruned_for_watch(project_root, config, path) {
+            continue;
+        }
+
+        let Ok(metadata) = fs::metadata(path) else { continue };
+        let Ok(modified) = metadata.modified() else { continue };
+        let modified_time: DateTime<Utc> = modified.into();
+        let extracted = extract_url_patterns(path);
+
+        cache.urls.insert(
+            key,
+            UrlEntry {
+                patterns: extracted.patterns,
+                includes: extracted.includes,
+                mtime: modified_time,
+            },
+        );
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +597,13 @@ mod tests {
         path
     }
 
+    fn pattern_names(urls: &HashMap<String, UrlEntry>) -> Vec<&str> {
+        urls.values()
+            .flat_map(|entry| &entry.patterns)
+            .filter_map(|pattern| pattern.name.as_deref())
+            .collect()
+    }
+
     fn setup_test_project() -> TempDir {
         let temp_dir = TempDir::new().expect("Failed to create temp directory");
         
@@ -165,28 +637,25 @@ mod tests {
         let cache_path = project_dir.path().join(".djazzy_cache.json");
         
         let mut cache = Cache::new(HashMap::new());
-        let urls = find_urls_py_files(project_dir.path(), &mut cache.urls);
+        let scan_config = ScanConfig::default_for(project_dir.path());
+        let urls = find_urls_py_files(project_dir.path(), &scan_config, &mut cache.urls);
         cache.urls.extend(urls);
         cache.save_to_file(&cache_path);
 
         assert!(cache_path.exists());
         let loaded_cache = Cache::load_from_file(&cache_path);
         assert_eq!(loaded_cache.urls.len(), 1);
-        assert!(loaded_cache.urls.values().any(|entry| entry.patterns.contains(&"home".to_string())));
+        assert!(pattern_names(&loaded_cache.urls).contains(&"home"));
     }
 
     #[test]
     fn test_ignored_directories() {
         let project_dir = setup_test_project();
         let mut cache = Cache::new(HashMap::new());
-        let urls = find_urls_py_files(project_dir.path(), &mut cache.urls);
+        let scan_config = ScanConfig::default_for(project_dir.path());
+        let urls = find_urls_py_files(project_dir.path(), &scan_config, &mut cache.urls);
 
-        assert!(!urls.values().any(|entry| entry.patterns.contains(&"ignored".to_string())));
-        
-        let all_patterns: Vec<_> = urls.values()
-            .flat_map(|entry| &entry.patterns)
-            .collect();
-        assert_eq!(all_patterns, vec!["home"]);
+        assert_eq!(pattern_names(&urls), vec!["home"]);
     }
 
     #[test]
@@ -195,7 +664,8 @@ mod tests {
         let cache_path = project_dir.path().join(".djazzy_cache.json");
         
         let mut initial_cache = Cache::new(HashMap::new());
-        let urls = find_urls_py_files(project_dir.path(), &mut initial_cache.urls);
+        let scan_config = ScanConfig::default_for(project_dir.path());
+        let urls = find_urls_py_files(project_dir.path(), &scan_config, &mut initial_cache.urls);
         initial_cache.urls.extend(urls);
         initial_cache.save_to_file(&cache_path);
 
@@ -208,14 +678,221 @@ mod tests {
         "#);
 
         let mut updated_cache = Cache::load_from_file(&cache_path);
-        let new_urls = find_urls_py_files(project_dir.path(), &mut updated_cache.urls);
+        let new_urls = find_urls_py_files(project_dir.path(), &scan_config, &mut updated_cache.urls);
         updated_cache.urls.extend(new_urls);
 
         assert_eq!(updated_cache.urls.len(), 1);
-        let patterns: Vec<_> = updated_cache.urls.values()
-            .flat_map(|entry| &entry.patterns)
-            .collect();
-        assert!(patterns.contains(&&"home".to_string()));
-        assert!(patterns.contains(&&"new-view".to_string()));
+        let names = pattern_names(&updated_cache.urls);
+        assert!(names.contains(&"home"));
+        assert!(names.contains(&"new-view"));
+    }
+
+    #[test]
+    fn test_custom_include_glob() {
+        let project_dir = setup_test_project();
+        create_test_file(&project_dir.path().join("app1"), "api_urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.api_root, name="api-root"),
+            ]
+        "#);
+
+        let config = ScanConfig::new(
+            project_dir.path(),
+            &["**/api_urls.py".to_string()],
+            &[],
+        );
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &config, &mut cache);
+
+        assert_eq!(pattern_names(&urls), vec!["api-root"]);
+    }
+
+    #[test]
+    fn test_ignore_glob_is_anchored_to_root() {
+        let project_dir = setup_test_project();
+        fs::create_dir_all(project_dir.path().join("apps/app_a/tests")).unwrap();
+        create_test_file(&project_dir.path().join("apps/app_a/tests"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.excluded, name="excluded"),
+            ]
+        "#);
+
+        // Written the same way an include pattern would be, with no "**/"
+        // prefix: it should still anchor to the project root rather than
+        // being tested against the full absolute path.
+        let config = ScanConfig::new(
+            project_dir.path(),
+            &["**/urls.py".to_string()],
+            &["apps/*/tests".to_string()],
+        );
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &config, &mut cache);
+
+        assert!(!pattern_names(&urls).contains(&"excluded"));
+        assert!(pattern_names(&urls).contains(&"home"));
+    }
+
+    #[test]
+    fn test_include_glob_star_does_not_cross_directories() {
+        let project_dir = setup_test_project();
+        fs::create_dir_all(project_dir.path().join("app/direct")).unwrap();
+        fs::create_dir_all(project_dir.path().join("app/nested/deeper")).unwrap();
+        create_test_file(&project_dir.path().join("app/direct"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.direct, name="direct"),
+            ]
+        "#);
+        create_test_file(&project_dir.path().join("app/nested/deeper"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.too_deep, name="too-deep"),
+            ]
+        "#);
+
+        let config = ScanConfig::new(
+            project_dir.path(),
+            &["app/*/urls.py".to_string()],
+            &[],
+        );
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &config, &mut cache);
+
+        assert_eq!(pattern_names(&urls), vec!["direct"]);
+    }
+
+    #[test]
+    fn test_bare_ignore_pattern_matches_at_any_depth() {
+        let project_dir = setup_test_project();
+        fs::create_dir_all(project_dir.path().join("node_modules")).unwrap();
+        create_test_file(&project_dir.path().join("node_modules"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.vendored, name="vendored"),
+            ]
+        "#);
+
+        // No "**/" prefix, and no "/" at all: should still be ignored
+        // wherever it occurs, the same as a `.gitignore` entry would be.
+        let config = ScanConfig::new(
+            project_dir.path(),
+            &["**/urls.py".to_string()],
+            &["node_modules".to_string()],
+        );
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &config, &mut cache);
+
+        assert!(!pattern_names(&urls).contains(&"vendored"));
+        assert!(pattern_names(&urls).contains(&"home"));
+    }
+
+    #[test]
+    fn test_respects_gitignore() {
+        let project_dir = setup_test_project();
+        fs::create_dir(project_dir.path().join("vendor")).unwrap();
+        create_test_file(&project_dir.path().join("vendor"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.vendored, name="vendored"),
+            ]
+        "#);
+        create_test_file(project_dir.path(), ".gitignore", "vendor/\n");
+
+        let config = ScanConfig::default_for(project_dir.path());
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &config, &mut cache);
+
+        let names = pattern_names(&urls);
+        assert!(!names.contains(&"vendored"));
+        assert!(names.contains(&"home"));
+    }
+
+    #[test]
+    fn test_watch_changes_respect_gitignore() {
+        let project_dir = setup_test_project();
+        fs::create_dir(project_dir.path().join("vendor")).unwrap();
+        let vendored_path = create_test_file(&project_dir.path().join("vendor"), "urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.vendored, name="vendored"),
+            ]
+        "#);
+        create_test_file(project_dir.path(), ".gitignore", "vendor/\n");
+
+        let config = ScanConfig::default_for(project_dir.path());
+        let mut cache = Cache::new(HashMap::new());
+        let mut touched = HashSet::new();
+        touched.insert(vendored_path);
+
+        apply_watch_changes(project_dir.path(), &config, &mut cache, &touched);
+
+        assert!(cache.urls.is_empty());
+    }
+
+    #[test]
+    fn test_watch_changes_respect_hardcoded_ignore_dirs() {
+        let project_dir = setup_test_project();
+        let venv_urls_path = project_dir.path().join(".venv").join("urls.py");
+
+        let config = ScanConfig::default_for(project_dir.path());
+        let mut cache = Cache::new(HashMap::new());
+        let mut touched = HashSet::new();
+        touched.insert(venv_urls_path);
+
+        apply_watch_changes(project_dir.path(), &config, &mut cache, &touched);
+
+        assert!(cache.urls.is_empty());
+    }
+
+    #[test]
+    fn test_config_discovery_walks_up_parents() {
+        let project_dir = setup_test_project();
+        create_test_file(project_dir.path(), "djazzy.json", r#"{"url_filenames": ["api_urls.py"]}"#);
+
+        let nested_start = project_dir.path().join("app1").join("migrations");
+        let mut finder = ConfigFinder::new();
+
+        let found = finder.find_config(&nested_start);
+        assert_eq!(found, Some(project_dir.path().join("djazzy.json")));
+    }
+
+    #[test]
+    fn test_resolve_applies_url_filenames_from_config() {
+        let project_dir = setup_test_project();
+        create_test_file(project_dir.path(), "djazzy.json", r#"{"url_filenames": ["api_urls.py"]}"#);
+        create_test_file(&project_dir.path().join("app1"), "api_urls.py", r#"
+            from django.urls import path
+            urlpatterns = [
+                path("", views.api_root, name="api-root"),
+            ]
+        "#);
+
+        let mut finder = ConfigFinder::new();
+        let (scan_config, _cache_path) = ScanConfig::resolve(project_dir.path(), &mut finder);
+
+        let mut cache = HashMap::new();
+        let urls = find_urls_py_files(project_dir.path(), &scan_config, &mut cache);
+
+        let names = pattern_names(&urls);
+        assert!(names.contains(&"api-root"));
+        // Config only adds url_filenames; the default "urls.py" include should still apply.
+        assert!(names.contains(&"home"));
+    }
+
+    #[test]
+    fn test_resolve_creates_missing_cache_path_parent() {
+        let project_dir = setup_test_project();
+        create_test_file(project_dir.path(), "djazzy.json", r#"{"cache_path": "build/cache/urls.json"}"#);
+
+        let mut finder = ConfigFinder::new();
+        let (_scan_config, cache_path) = ScanConfig::resolve(project_dir.path(), &mut finder);
+        assert_eq!(cache_path, project_dir.path().join("build/cache/urls.json"));
+
+        let cache = Cache::new(HashMap::new());
+        cache.save_to_file(&cache_path);
+
+        assert!(cache_path.exists());
     }
 }